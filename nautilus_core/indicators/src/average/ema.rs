@@ -0,0 +1,103 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use crate::{average::MovingAverage, indicator::Indicator};
+
+/// An indicator which calculates an exponential moving average (EMA) across a rolling window.
+#[repr(C)]
+#[derive(Debug)]
+pub struct ExponentialMovingAverage {
+    /// The rolling window period for the indicator.
+    pub period: usize,
+    /// The smoothing factor applied to the most recent value.
+    pub alpha: f64,
+    /// The last value produced by the indicator.
+    pub value: f64,
+    pub(crate) has_inputs: bool,
+    pub(crate) initialized: bool,
+    count: usize,
+}
+
+impl Display for ExponentialMovingAverage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for ExponentialMovingAverage {
+    fn name(&self) -> String {
+        stringify!(ExponentialMovingAverage).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl MovingAverage for ExponentialMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        if !self.has_inputs {
+            self.has_inputs = true;
+            self.value = value;
+        } else {
+            self.value = self.alpha.mul_add(value, (1.0 - self.alpha) * self.value);
+        }
+
+        self.count += 1;
+        if !self.initialized && self.count >= self.period {
+            self.initialized = true;
+        }
+    }
+}
+
+impl ExponentialMovingAverage {
+    /// Creates a new [`ExponentialMovingAverage`] instance.
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        Self {
+            period,
+            alpha: 2.0 / (period as f64 + 1.0),
+            value: 0.0,
+            has_inputs: false,
+            initialized: false,
+            count: 0,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod ema;
+pub mod sma;
+
+use std::fmt::Display;
+
+pub use ema::ExponentialMovingAverage;
+pub use sma::SimpleMovingAverage;
+use strum::{Display as StrumDisplay, EnumString, FromRepr};
+
+use crate::indicator::Indicator;
+
+/// The moving average calculation method used by indicators that accept a configurable
+/// `ma_type`.
+#[repr(C)]
+#[derive(
+    Copy, Clone, Debug, Default, Hash, PartialEq, Eq, StrumDisplay, EnumString, FromRepr,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum MovingAverageType {
+    /// Simple moving average.
+    #[default]
+    Simple,
+    /// Exponential moving average.
+    Exponential,
+}
+
+/// A moving average that can be updated incrementally with raw values.
+pub trait MovingAverage: Indicator {
+    /// Returns the lookback period (number of inputs) of the moving average.
+    fn period(&self) -> usize;
+
+    /// Returns the current value of the moving average.
+    fn value(&self) -> f64;
+
+    /// Returns the count of inputs counted toward the current value; a windowed average
+    /// (such as [`SimpleMovingAverage`]) counts only the inputs still in its window.
+    fn count(&self) -> usize;
+
+    /// Updates the moving average with the given raw value.
+    fn update_raw(&mut self, value: f64);
+}
+
+/// Constructs a [`Box<dyn MovingAverage>`] for the given [`MovingAverageType`] and period.
+#[must_use]
+pub fn indicator_ma_from_type(ma_type: MovingAverageType, period: usize) -> Box<dyn MovingAverage> {
+    match ma_type {
+        MovingAverageType::Simple => Box::new(SimpleMovingAverage::new(period)),
+        MovingAverageType::Exponential => Box::new(ExponentialMovingAverage::new(period)),
+    }
+}
+
+impl Display for dyn MovingAverage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MovingAverage(period={})", self.period())
+    }
+}
@@ -0,0 +1,105 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, fmt::Display};
+
+use crate::{average::MovingAverage, indicator::Indicator};
+
+/// An indicator which calculates a simple moving average (SMA) across a rolling window.
+#[repr(C)]
+#[derive(Debug)]
+pub struct SimpleMovingAverage {
+    /// The rolling window period for the indicator.
+    pub period: usize,
+    /// The last value produced by the indicator.
+    pub value: f64,
+    pub(crate) has_inputs: bool,
+    pub(crate) initialized: bool,
+    inputs: VecDeque<f64>,
+    sum: f64,
+}
+
+impl Display for SimpleMovingAverage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for SimpleMovingAverage {
+    fn name(&self) -> String {
+        stringify!(SimpleMovingAverage).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.sum = 0.0;
+        self.inputs.clear();
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl MovingAverage for SimpleMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.inputs.len()
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        if self.inputs.len() == self.period {
+            let old = self.inputs.pop_front().unwrap();
+            self.sum -= old;
+        }
+
+        self.inputs.push_back(value);
+        self.sum += value;
+        self.has_inputs = true;
+        self.value = self.sum / self.inputs.len() as f64;
+
+        if !self.initialized && self.inputs.len() >= self.period {
+            self.initialized = true;
+        }
+    }
+}
+
+impl SimpleMovingAverage {
+    /// Creates a new [`SimpleMovingAverage`] instance.
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        Self {
+            period,
+            value: 0.0,
+            has_inputs: false,
+            initialized: false,
+            inputs: VecDeque::with_capacity(period),
+            sum: 0.0,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+/// The common interface implemented by all indicators in the crate.
+///
+/// Indicator-specific update signatures (`update_raw`, `handle_bar`, ...) are defined
+/// inherently on each indicator, since the set of raw inputs they consume differs.
+pub trait Indicator {
+    /// Returns the indicator name.
+    fn name(&self) -> String;
+
+    /// Returns whether the indicator has received any inputs.
+    fn has_inputs(&self) -> bool;
+
+    /// Returns whether the indicator has been initialized (warmed up).
+    fn initialized(&self) -> bool;
+
+    /// Resets the indicator to its initial unfitted state.
+    fn reset(&mut self);
+}
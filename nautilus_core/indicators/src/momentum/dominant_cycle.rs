@@ -0,0 +1,218 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, f64::consts::PI, fmt::Display, sync::Arc};
+
+use nautilus_model::data::Bar;
+use rustfft::{num_complex::Complex64, Fft, FftPlanner};
+
+use crate::indicator::Indicator;
+
+/// An indicator which estimates the dominant cycle length of a bar series using Welch's method:
+/// the closing-price window is split into overlapping (50%) segments, each is demeaned and
+/// Hann-windowed, the real FFT magnitude-squared periodograms are averaged across segments, and
+/// the DC bin is zeroed before locating the peak to avoid residual mean leakage dominating the
+/// estimate.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DominantCycle {
+    /// The number of closes held in the analysis window.
+    pub window: usize,
+    /// The length of each overlapping segment used for Welch averaging.
+    pub segment_length: usize,
+    /// The estimated dominant cycle period, in bars.
+    pub period: f64,
+    /// The averaged spectral power at the dominant cycle's peak bin.
+    pub power: f64,
+    /// Whether the indicator has received inputs.
+    pub has_inputs: bool,
+    /// Whether the indicator has been initialized (warmed up).
+    pub initialized: bool,
+    closes: VecDeque<f64>,
+    fft: Arc<dyn Fft<f64>>,
+    hann_window: Vec<f64>,
+}
+
+impl Display for DominantCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.window, self.segment_length)
+    }
+}
+
+impl Indicator for DominantCycle {
+    fn name(&self) -> String {
+        stringify!(DominantCycle).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn reset(&mut self) {
+        self.closes.clear();
+        self.period = 0.0;
+        self.power = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl DominantCycle {
+    /// Creates a new [`DominantCycle`] instance.
+    ///
+    /// `segment_length` must be less than or equal to `window`; segments overlap by 50%.
+    #[must_use]
+    pub fn new(window: usize, segment_length: usize) -> Self {
+        let mut planner = FftPlanner::<f64>::new();
+        let fft = planner.plan_fft_forward(segment_length);
+        let hann_window = (0..segment_length)
+            .map(|i| {
+                0.5 * (1.0
+                    - (2.0 * PI * i as f64 / (segment_length.saturating_sub(1)) as f64).cos())
+            })
+            .collect();
+
+        Self {
+            window,
+            segment_length,
+            period: 0.0,
+            power: 0.0,
+            has_inputs: false,
+            initialized: false,
+            closes: VecDeque::with_capacity(window),
+            fft,
+            hann_window,
+        }
+    }
+
+    /// Updates the indicator with the given raw close value.
+    pub fn update_raw(&mut self, close: f64) {
+        self.has_inputs = true;
+
+        if self.closes.len() == self.window {
+            self.closes.pop_front();
+        }
+        self.closes.push_back(close);
+
+        if self.closes.len() == self.window {
+            if let Some((period, power)) = self.estimate_dominant_cycle() {
+                self.period = period;
+                self.power = power;
+                self.initialized = true;
+            }
+        }
+    }
+
+    /// Updates the indicator with the given bar's close price.
+    pub fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.close.as_f64());
+    }
+
+    /// Computes the Welch-averaged periodogram across overlapping segments of the current
+    /// window and returns `(period, power)` for the peak bin, or `None` if the window yielded
+    /// no full segment (e.g. `segment_length` larger than `window`).
+    fn estimate_dominant_cycle(&self) -> Option<(f64, f64)> {
+        let closes: Vec<f64> = self.closes.iter().copied().collect();
+        let step = (self.segment_length / 2).max(1);
+        let half = self.segment_length / 2;
+        let mut averaged = vec![0.0_f64; half + 1];
+        let mut segment_count = 0usize;
+
+        let mut start = 0;
+        while start + self.segment_length <= closes.len() {
+            let segment = &closes[start..start + self.segment_length];
+            let mean = segment.iter().sum::<f64>() / segment.len() as f64;
+
+            let mut buffer: Vec<Complex64> = segment
+                .iter()
+                .zip(&self.hann_window)
+                .map(|(value, window)| Complex64::new((value - mean) * window, 0.0))
+                .collect();
+
+            self.fft.process(&mut buffer);
+
+            for (bin, value) in averaged.iter_mut().enumerate() {
+                *value += buffer[bin].norm_sqr();
+            }
+
+            segment_count += 1;
+            start += step;
+        }
+
+        if segment_count == 0 {
+            return None;
+        }
+
+        for value in &mut averaged {
+            *value /= segment_count as f64;
+        }
+        averaged[0] = 0.0; // Zero the DC bin so mean-removal leakage doesn't dominate the peak.
+
+        let (peak_bin, &peak_power) = averaged
+            .iter()
+            .enumerate()
+            .skip(1)
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .unwrap_or((0, &0.0));
+
+        if peak_bin == 0 {
+            Some((0.0, peak_power))
+        } else {
+            Some((self.segment_length as f64 / peak_bin as f64, peak_power))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dominant_cycle_initial_state() {
+        let cycle = DominantCycle::new(64, 32);
+        assert_eq!(cycle.window, 64);
+        assert_eq!(cycle.segment_length, 32);
+        assert!(!cycle.initialized);
+    }
+
+    #[test]
+    fn test_dominant_cycle_detects_known_period() {
+        let mut cycle = DominantCycle::new(64, 32);
+        let true_period = 8.0;
+        for i in 0..64 {
+            let close = (2.0 * PI * i as f64 / true_period).sin();
+            cycle.update_raw(close);
+        }
+        assert!(cycle.initialized);
+        assert!((cycle.period - true_period).abs() <= 1.0);
+        assert!(cycle.power > 0.0);
+    }
+
+    #[test]
+    fn test_dominant_cycle_reset() {
+        let mut cycle = DominantCycle::new(16, 8);
+        for i in 0..16 {
+            cycle.update_raw(i as f64);
+        }
+        cycle.reset();
+        assert!(!cycle.has_inputs);
+        assert!(!cycle.initialized);
+        assert_eq!(cycle.period, 0.0);
+    }
+}
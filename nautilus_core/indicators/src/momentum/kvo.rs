@@ -0,0 +1,224 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::data::Bar;
+
+use crate::{
+    average::{indicator_ma_from_type, MovingAverage, MovingAverageType},
+    indicator::Indicator,
+};
+
+/// The Klinger Volume Oscillator (KVO) measures long-term money flow while remaining
+/// sensitive enough to detect short-term reversals, by comparing volume to price movement
+/// and converting the result into an oscillator via a fast and slow moving average (of the
+/// configured `ma_type`, simple by default) of the volume force.
+#[repr(C)]
+#[derive(Debug)]
+pub struct KlingerVolumeOscillator {
+    /// The period for the fast moving average of the volume force.
+    pub fast_period: usize,
+    /// The period for the slow moving average of the volume force.
+    pub slow_period: usize,
+    /// The period for the signal moving average of the oscillator value.
+    pub signal_period: usize,
+    /// The moving average type used for the fast, slow, and signal lines.
+    pub ma_type: MovingAverageType,
+    /// The last oscillator value (fast volume-force MA minus slow volume-force MA).
+    pub value: f64,
+    /// The last signal-line value (moving average of `value`).
+    pub signal: f64,
+    /// Whether the indicator has received inputs.
+    pub has_inputs: bool,
+    /// Whether the indicator has been initialized (warmed up).
+    pub initialized: bool,
+    fast_ma: Box<dyn MovingAverage>,
+    slow_ma: Box<dyn MovingAverage>,
+    signal_ma: Box<dyn MovingAverage>,
+    previous_hlc: Option<f64>,
+    previous_trend: i8,
+    previous_dm: f64,
+    cm: f64,
+}
+
+impl Display for KlingerVolumeOscillator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{},{})",
+            self.name(),
+            self.fast_period,
+            self.slow_period,
+            self.signal_period,
+            self.ma_type
+        )
+    }
+}
+
+impl Indicator for KlingerVolumeOscillator {
+    fn name(&self) -> String {
+        stringify!(KlingerVolumeOscillator).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.signal = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
+        self.previous_hlc = None;
+        self.previous_trend = 0;
+        self.previous_dm = 0.0;
+        self.cm = 0.0;
+    }
+}
+
+impl KlingerVolumeOscillator {
+    /// Creates a new [`KlingerVolumeOscillator`] instance.
+    #[must_use]
+    pub fn new(
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+        ma_type: Option<MovingAverageType>,
+    ) -> Self {
+        let ma_type = ma_type.unwrap_or_default();
+        Self {
+            fast_period,
+            slow_period,
+            signal_period,
+            ma_type,
+            value: 0.0,
+            signal: 0.0,
+            has_inputs: false,
+            initialized: false,
+            fast_ma: indicator_ma_from_type(ma_type, fast_period),
+            slow_ma: indicator_ma_from_type(ma_type, slow_period),
+            signal_ma: indicator_ma_from_type(ma_type, signal_period),
+            previous_hlc: None,
+            previous_trend: 0,
+            previous_dm: 0.0,
+            cm: 0.0,
+        }
+    }
+
+    /// The oscillator value minus its signal line.
+    #[must_use]
+    pub fn histogram(&self) -> f64 {
+        self.value - self.signal
+    }
+
+    /// Updates the indicator with the given raw high, low, close, and volume values.
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64, volume: f64) {
+        self.has_inputs = true;
+
+        let dm = high - low;
+        let hlc = high + low + close;
+        let trend = match self.previous_hlc {
+            Some(previous_hlc) if hlc < previous_hlc => -1,
+            Some(_) => 1,
+            None => 1,
+        };
+
+        self.cm = if trend == self.previous_trend {
+            self.cm + dm
+        } else {
+            self.previous_dm + dm
+        };
+
+        let volume_force = if self.cm > 0.0 {
+            volume * (2.0 * (dm / self.cm) - 1.0).abs() * f64::from(trend) * 100.0
+        } else {
+            0.0
+        };
+
+        self.fast_ma.update_raw(volume_force);
+        self.slow_ma.update_raw(volume_force);
+        self.value = self.fast_ma.value() - self.slow_ma.value();
+
+        self.signal_ma.update_raw(self.value);
+        self.signal = self.signal_ma.value();
+
+        self.previous_hlc = Some(hlc);
+        self.previous_trend = trend;
+        self.previous_dm = dm;
+
+        if !self.initialized && self.fast_ma.initialized() && self.slow_ma.initialized() {
+            self.initialized = true;
+        }
+    }
+
+    /// Updates the indicator with the given bar.
+    pub fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(
+            bar.high.as_f64(),
+            bar.low.as_f64(),
+            bar.close.as_f64(),
+            bar.volume.as_f64(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kvo_initial_state() {
+        let kvo = KlingerVolumeOscillator::new(34, 55, 13, None);
+        assert_eq!(kvo.fast_period, 34);
+        assert_eq!(kvo.slow_period, 55);
+        assert_eq!(kvo.signal_period, 13);
+        assert!(!kvo.initialized);
+        assert!(!kvo.has_inputs);
+    }
+
+    #[test]
+    fn test_kvo_update_raw() {
+        let mut kvo = KlingerVolumeOscillator::new(2, 3, 2, None);
+        kvo.update_raw(10.0, 8.0, 9.0, 100.0);
+        kvo.update_raw(11.0, 9.0, 10.0, 120.0);
+        kvo.update_raw(12.0, 10.0, 11.0, 130.0);
+        assert!(kvo.has_inputs);
+
+        // `ma_type` defaults to `MovingAverageType::Simple`, so these expected values were
+        // independently derived by replaying the same SMA/trend/cm recurrence used by
+        // `update_raw` over this fixed input sequence.
+        assert!((kvo.value - (-2611.111_111_111_111_3)).abs() < 1e-9);
+        assert!((kvo.signal - (-1305.555_555_555_555_7)).abs() < 1e-9);
+        assert!((kvo.histogram() - (-1305.555_555_555_555_7)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_kvo_reset() {
+        let mut kvo = KlingerVolumeOscillator::new(2, 3, 2, None);
+        kvo.update_raw(10.0, 8.0, 9.0, 100.0);
+        kvo.reset();
+        assert!(!kvo.has_inputs);
+        assert!(!kvo.initialized);
+        assert_eq!(kvo.value, 0.0);
+    }
+}
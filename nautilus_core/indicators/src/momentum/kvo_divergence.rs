@@ -0,0 +1,286 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, fmt::Display};
+
+use nautilus_model::data::Bar;
+
+use crate::{average::MovingAverageType, indicator::Indicator, momentum::kvo::KlingerVolumeOscillator};
+
+/// An indicator which detects classic price/KVO divergence for the [`KlingerVolumeOscillator`],
+/// and passes through its signal line and histogram (value minus signal).
+///
+/// Signal-line crossovers are not tracked: the signal and histogram getters report only the
+/// current values.
+///
+/// On each update the most recent closing-price swing highs/lows are compared against the
+/// KVO swing highs/lows over a configurable `lookback` window:
+/// - Bullish divergence (`+1`): price makes a lower swing low while the KVO makes a higher
+///   swing low.
+/// - Bearish divergence (`-1`): price makes a higher swing high while the KVO makes a lower
+///   swing high.
+/// - No divergence (`0`) otherwise.
+#[repr(C)]
+#[derive(Debug)]
+pub struct KvoDivergence {
+    /// The period for the fast moving average of the volume force.
+    pub fast_period: usize,
+    /// The period for the slow moving average of the volume force.
+    pub slow_period: usize,
+    /// The period for the signal moving average of the oscillator value.
+    pub signal_period: usize,
+    /// The number of recent bars scanned for swing highs/lows.
+    pub lookback: usize,
+    /// The moving average type used internally by the KVO.
+    pub ma_type: MovingAverageType,
+    /// The most recently detected divergence: `1` (bullish), `-1` (bearish), or `0` (none).
+    pub divergence: i8,
+    /// Whether the indicator has received inputs.
+    pub has_inputs: bool,
+    /// Whether the indicator has been initialized (warmed up).
+    pub initialized: bool,
+    kvo: KlingerVolumeOscillator,
+    closes: VecDeque<f64>,
+    kvo_values: VecDeque<f64>,
+}
+
+impl Display for KvoDivergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{},{})",
+            self.name(),
+            self.fast_period,
+            self.slow_period,
+            self.signal_period,
+            self.lookback
+        )
+    }
+}
+
+impl Indicator for KvoDivergence {
+    fn name(&self) -> String {
+        stringify!(KvoDivergence).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn reset(&mut self) {
+        self.kvo.reset();
+        self.closes.clear();
+        self.kvo_values.clear();
+        self.divergence = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl KvoDivergence {
+    /// Creates a new [`KvoDivergence`] instance.
+    #[must_use]
+    pub fn new(
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+        lookback: usize,
+        ma_type: Option<MovingAverageType>,
+    ) -> Self {
+        let ma_type = ma_type.unwrap_or_default();
+        Self {
+            fast_period,
+            slow_period,
+            signal_period,
+            lookback,
+            ma_type,
+            divergence: 0,
+            has_inputs: false,
+            initialized: false,
+            kvo: KlingerVolumeOscillator::new(fast_period, slow_period, signal_period, Some(ma_type)),
+            closes: VecDeque::with_capacity(lookback),
+            kvo_values: VecDeque::with_capacity(lookback),
+        }
+    }
+
+    /// The underlying oscillator value.
+    #[must_use]
+    pub fn value(&self) -> f64 {
+        self.kvo.value
+    }
+
+    /// The underlying signal-line value.
+    #[must_use]
+    pub fn signal(&self) -> f64 {
+        self.kvo.signal
+    }
+
+    /// The underlying oscillator value minus its signal line.
+    #[must_use]
+    pub fn histogram(&self) -> f64 {
+        self.kvo.histogram()
+    }
+
+    /// Updates the indicator with the given raw high, low, close, and volume values.
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64, volume: f64) {
+        self.has_inputs = true;
+        self.kvo.update_raw(high, low, close, volume);
+
+        if !self.kvo.initialized() {
+            return;
+        }
+
+        if self.closes.len() == self.lookback {
+            self.closes.pop_front();
+            self.kvo_values.pop_front();
+        }
+        self.closes.push_back(close);
+        self.kvo_values.push_back(self.kvo.value);
+
+        if self.closes.len() == self.lookback {
+            self.divergence = Self::detect_divergence(&self.closes, &self.kvo_values);
+            self.initialized = true;
+        }
+    }
+
+    /// Updates the indicator with the given bar.
+    pub fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(
+            bar.high.as_f64(),
+            bar.low.as_f64(),
+            bar.close.as_f64(),
+            bar.volume.as_f64(),
+        );
+    }
+
+    /// Independently scans `prices` and `oscillator` (same length, oldest first) for their own
+    /// two most recent swing highs and two most recent swing lows, then compares the slope
+    /// signs between the two series to flag classic divergence. Price and oscillator pivots
+    /// are not expected to land on the same index, so each series is scanned on its own.
+    fn detect_divergence(prices: &VecDeque<f64>, oscillator: &VecDeque<f64>) -> i8 {
+        let prices: Vec<f64> = prices.iter().copied().collect();
+        let oscillator: Vec<f64> = oscillator.iter().copied().collect();
+        let (price_highs, price_lows) = swing_indices(&prices);
+        let (osc_highs, osc_lows) = swing_indices(&oscillator);
+
+        if let ([.., p1, p2], [.., o1, o2]) = (&price_highs[..], &osc_highs[..]) {
+            let price_slope = prices[*p2] - prices[*p1];
+            let osc_slope = oscillator[*o2] - oscillator[*o1];
+            if price_slope > 0.0 && osc_slope < 0.0 {
+                return -1; // Bearish divergence.
+            }
+        }
+
+        if let ([.., p1, p2], [.., o1, o2]) = (&price_lows[..], &osc_lows[..]) {
+            let price_slope = prices[*p2] - prices[*p1];
+            let osc_slope = oscillator[*o2] - oscillator[*o1];
+            if price_slope < 0.0 && osc_slope > 0.0 {
+                return 1; // Bullish divergence.
+            }
+        }
+
+        0
+    }
+}
+
+/// Returns the indices of swing highs and swing lows (simple 3-point pivots) in `values`.
+fn swing_indices(values: &[f64]) -> (Vec<usize>, Vec<usize>) {
+    let mut highs = Vec::new();
+    let mut lows = Vec::new();
+
+    for i in 1..values.len().saturating_sub(1) {
+        if values[i] > values[i - 1] && values[i] > values[i + 1] {
+            highs.push(i);
+        } else if values[i] < values[i - 1] && values[i] < values[i + 1] {
+            lows.push(i);
+        }
+    }
+
+    (highs, lows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kvo_divergence_initial_state() {
+        let div = KvoDivergence::new(2, 3, 2, 10, None);
+        assert_eq!(div.lookback, 10);
+        assert_eq!(div.divergence, 0);
+        assert!(!div.initialized);
+    }
+
+    #[test]
+    fn test_kvo_divergence_update_raw() {
+        let mut div = KvoDivergence::new(2, 3, 2, 6, None);
+        for i in 0..20 {
+            let base = 10.0 + (i as f64 * 0.3).sin() * 2.0;
+            div.update_raw(base + 1.0, base - 1.0, base, 100.0 + i as f64);
+        }
+        assert!(div.has_inputs);
+        assert!(div.initialized);
+    }
+
+    #[test]
+    fn test_detect_divergence_bearish_when_price_higher_high_and_kvo_lower_high() {
+        // Price makes a higher swing high (3.0 -> 4.0) while the KVO makes a lower swing
+        // high (5.0 -> 3.0) over the same window: classic bearish divergence.
+        let prices = VecDeque::from(vec![1.0, 3.0, 1.0, 4.0, 1.0]);
+        let kvo_values = VecDeque::from(vec![1.0, 5.0, 1.0, 3.0, 1.0]);
+
+        assert_eq!(KvoDivergence::detect_divergence(&prices, &kvo_values), -1);
+    }
+
+    #[test]
+    fn test_detect_divergence_bullish_when_price_lower_low_and_kvo_higher_low() {
+        // Price makes a lower swing low (1.0 -> 0.5) while the KVO makes a higher swing
+        // low (0.5 -> 2.0) over the same window: classic bullish divergence.
+        let prices = VecDeque::from(vec![3.0, 1.0, 3.0, 0.5, 3.0]);
+        let kvo_values = VecDeque::from(vec![3.0, 0.5, 3.0, 2.0, 3.0]);
+
+        assert_eq!(KvoDivergence::detect_divergence(&prices, &kvo_values), 1);
+    }
+
+    #[test]
+    fn test_detect_divergence_none_when_price_and_kvo_pivots_agree() {
+        let prices = VecDeque::from(vec![1.0, 3.0, 1.0, 4.0, 1.0]);
+        let kvo_values = VecDeque::from(vec![1.0, 3.0, 1.0, 4.0, 1.0]);
+
+        assert_eq!(KvoDivergence::detect_divergence(&prices, &kvo_values), 0);
+    }
+
+    #[test]
+    fn test_swing_indices() {
+        let values = vec![1.0, 3.0, 1.0, 4.0, 0.5, 2.0];
+        let (highs, lows) = swing_indices(&values);
+        assert_eq!(highs, vec![1, 3]);
+        assert_eq!(lows, vec![2, 4]);
+    }
+
+    #[test]
+    fn test_kvo_divergence_reset() {
+        let mut div = KvoDivergence::new(2, 3, 2, 6, None);
+        div.update_raw(11.0, 9.0, 10.0, 100.0);
+        div.reset();
+        assert!(!div.has_inputs);
+        assert!(!div.initialized);
+        assert_eq!(div.divergence, 0);
+    }
+}
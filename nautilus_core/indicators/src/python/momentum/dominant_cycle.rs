@@ -0,0 +1,89 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::data::Bar;
+use pyo3::prelude::*;
+
+use crate::{indicator::Indicator, momentum::dominant_cycle::DominantCycle};
+
+#[pymethods]
+impl DominantCycle {
+    #[new]
+    #[must_use]
+    pub fn py_new(window: usize, segment_length: usize) -> Self {
+        Self::new(window, segment_length)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("DominantCycle({},{})", self.window, self.segment_length)
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "window")]
+    const fn py_window(&self) -> usize {
+        self.window
+    }
+
+    #[getter]
+    #[pyo3(name = "segment_length")]
+    const fn py_segment_length(&self) -> usize {
+        self.segment_length
+    }
+
+    #[getter]
+    #[pyo3(name = "has_inputs")]
+    fn py_has_inputs(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[getter]
+    #[pyo3(name = "period")]
+    const fn py_period(&self) -> f64 {
+        self.period
+    }
+
+    #[getter]
+    #[pyo3(name = "power")]
+    const fn py_power(&self) -> f64 {
+        self.power
+    }
+
+    #[getter]
+    #[pyo3(name = "initialized")]
+    const fn py_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[pyo3(name = "update_raw")]
+    fn py_update_raw(&mut self, close: f64) {
+        self.update_raw(close);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn py_handle_bar(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+}
@@ -77,6 +77,18 @@ impl KlingerVolumeOscillator {
         self.value
     }
 
+    #[getter]
+    #[pyo3(name = "signal")]
+    const fn py_signal(&self) -> f64 {
+        self.signal
+    }
+
+    #[getter]
+    #[pyo3(name = "histogram")]
+    fn py_histogram(&self) -> f64 {
+        self.histogram()
+    }
+
     #[getter]
     #[pyo3(name = "initialized")]
     const fn py_initialized(&self) -> bool {
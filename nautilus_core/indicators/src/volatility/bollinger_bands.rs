@@ -0,0 +1,204 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, fmt::Display};
+
+use nautilus_model::data::Bar;
+
+use crate::{
+    average::{indicator_ma_from_type, MovingAverage, MovingAverageType},
+    indicator::Indicator,
+};
+
+/// An indicator which calculates Bollinger Bands: a middle moving-average band plus upper and
+/// lower bands offset by `k` standard deviations of price over the same rolling period, along
+/// with the derived `%B` and `bandwidth` outputs.
+#[repr(C)]
+#[derive(Debug)]
+pub struct BollingerBands {
+    /// The rolling window period for the indicator.
+    pub period: usize,
+    /// The standard deviation multiplier applied to the upper/lower bands.
+    pub k: f64,
+    /// The moving average type used for the middle band.
+    pub ma_type: MovingAverageType,
+    /// The middle band value (the moving average of price).
+    pub middle: f64,
+    /// The upper band value (`middle + k * std_dev`).
+    pub upper: f64,
+    /// The lower band value (`middle - k * std_dev`).
+    pub lower: f64,
+    /// `%B = (close - lower) / (upper - lower)`.
+    pub percent_b: f64,
+    /// `bandwidth = (upper - lower) / middle`.
+    pub bandwidth: f64,
+    /// Whether the indicator has received inputs.
+    pub has_inputs: bool,
+    /// Whether the indicator has been initialized (warmed up).
+    pub initialized: bool,
+    ma: Box<dyn MovingAverage>,
+    closes: VecDeque<f64>,
+}
+
+impl Display for BollingerBands {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{},{})", self.name(), self.period, self.k, self.ma_type)
+    }
+}
+
+impl Indicator for BollingerBands {
+    fn name(&self) -> String {
+        stringify!(BollingerBands).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.closes.clear();
+        self.middle = 0.0;
+        self.upper = 0.0;
+        self.lower = 0.0;
+        self.percent_b = 0.0;
+        self.bandwidth = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl BollingerBands {
+    /// Creates a new [`BollingerBands`] instance.
+    #[must_use]
+    pub fn new(period: usize, k: f64, ma_type: Option<MovingAverageType>) -> Self {
+        let ma_type = ma_type.unwrap_or_default();
+        Self {
+            period,
+            k,
+            ma_type,
+            middle: 0.0,
+            upper: 0.0,
+            lower: 0.0,
+            percent_b: 0.0,
+            bandwidth: 0.0,
+            has_inputs: false,
+            initialized: false,
+            ma: indicator_ma_from_type(ma_type, period),
+            closes: VecDeque::with_capacity(period),
+        }
+    }
+
+    /// Updates the indicator with the given raw close value.
+    pub fn update_raw(&mut self, close: f64) {
+        self.has_inputs = true;
+        self.ma.update_raw(close);
+
+        if self.closes.len() == self.period {
+            self.closes.pop_front();
+        }
+        self.closes.push_back(close);
+
+        if self.closes.len() == self.period {
+            self.middle = self.ma.value();
+
+            // Center the dispersion on `self.middle` (the configured `ma_type`'s own mean),
+            // not a separately-computed SMA mean, so the bands stay consistent when `ma_type`
+            // is `Exponential`.
+            let variance = self
+                .closes
+                .iter()
+                .map(|value| (value - self.middle).powi(2))
+                .sum::<f64>()
+                / self.period as f64;
+            let std_dev = variance.sqrt();
+
+            self.upper = self.k.mul_add(std_dev, self.middle);
+            self.lower = self.middle - self.k * std_dev;
+
+            let band_range = self.upper - self.lower;
+            self.percent_b = if band_range > 0.0 {
+                (close - self.lower) / band_range
+            } else {
+                0.0
+            };
+            self.bandwidth = if self.middle != 0.0 {
+                band_range / self.middle
+            } else {
+                0.0
+            };
+
+            self.initialized = true;
+        }
+    }
+
+    /// Updates the indicator with the given bar's close price.
+    pub fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.close.as_f64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bollinger_bands_initial_state() {
+        let bb = BollingerBands::new(20, 2.0, None);
+        assert_eq!(bb.period, 20);
+        assert_eq!(bb.k, 2.0);
+        assert!(!bb.initialized);
+    }
+
+    #[test]
+    fn test_bollinger_bands_flat_series() {
+        let mut bb = BollingerBands::new(5, 2.0, None);
+        for _ in 0..5 {
+            bb.update_raw(10.0);
+        }
+        assert!(bb.initialized);
+        assert_eq!(bb.middle, 10.0);
+        assert_eq!(bb.upper, 10.0);
+        assert_eq!(bb.lower, 10.0);
+        assert_eq!(bb.bandwidth, 0.0);
+    }
+
+    #[test]
+    fn test_bollinger_bands_percent_b_and_bandwidth() {
+        let mut bb = BollingerBands::new(3, 2.0, None);
+        bb.update_raw(1.0);
+        bb.update_raw(2.0);
+        bb.update_raw(3.0);
+        assert!(bb.initialized);
+        assert!(bb.upper > bb.middle);
+        assert!(bb.lower < bb.middle);
+        assert!(bb.percent_b >= 0.0 && bb.percent_b <= 1.0);
+        assert!(bb.bandwidth > 0.0);
+    }
+
+    #[test]
+    fn test_bollinger_bands_reset() {
+        let mut bb = BollingerBands::new(3, 2.0, None);
+        bb.update_raw(1.0);
+        bb.reset();
+        assert!(!bb.has_inputs);
+        assert!(!bb.initialized);
+        assert_eq!(bb.middle, 0.0);
+    }
+}
@@ -0,0 +1,24 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! `wasm-bindgen` wrappers exposing the crate's indicators to JavaScript/WASM hosts.
+//!
+//! These mirror the `#[pymethods]` surface under [`crate::python`] one-for-one: the indicator
+//! math stays crate-internal, and each wrapper is a thin `update_raw`/`handle_bar`/`reset`/getter
+//! shim so the same indicator logic can run in a browser or sandboxed WASM runtime without a
+//! Python interpreter.
+
+pub mod momentum;
+pub mod volatility;
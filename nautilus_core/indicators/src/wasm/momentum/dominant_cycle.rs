@@ -0,0 +1,80 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::data::Bar;
+use wasm_bindgen::{prelude::*, JsValue};
+
+use crate::{indicator::Indicator, momentum::dominant_cycle::DominantCycle};
+
+#[wasm_bindgen]
+impl DominantCycle {
+    #[wasm_bindgen(constructor)]
+    #[must_use]
+    pub fn new_js(window: usize, segment_length: usize) -> Self {
+        Self::new(window, segment_length)
+    }
+
+    #[wasm_bindgen(js_name = "name")]
+    pub fn name_js(&self) -> String {
+        self.name()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub const fn window(&self) -> usize {
+        self.window
+    }
+
+    #[wasm_bindgen(getter, js_name = "segmentLength")]
+    pub const fn segment_length_js(&self) -> usize {
+        self.segment_length
+    }
+
+    #[wasm_bindgen(getter, js_name = "hasInputs")]
+    pub fn has_inputs_js(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub const fn period(&self) -> f64 {
+        self.period
+    }
+
+    #[wasm_bindgen(getter)]
+    pub const fn power(&self) -> f64 {
+        self.power
+    }
+
+    #[wasm_bindgen(getter)]
+    pub const fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[wasm_bindgen(js_name = "updateRaw")]
+    pub fn update_raw_js(&mut self, close: f64) {
+        self.update_raw(close);
+    }
+
+    #[wasm_bindgen(js_name = "handleBar")]
+    pub fn handle_bar_js(&mut self, bar: JsValue) -> Result<(), JsValue> {
+        let bar: Bar = serde_wasm_bindgen::from_value(bar)?;
+        self.handle_bar(&bar);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "reset")]
+    pub fn reset_js(&mut self) {
+        self.reset();
+    }
+}
@@ -0,0 +1,97 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::data::Bar;
+use wasm_bindgen::{prelude::*, JsValue};
+
+use crate::{
+    average::MovingAverageType, indicator::Indicator, momentum::kvo::KlingerVolumeOscillator,
+};
+
+#[wasm_bindgen]
+impl KlingerVolumeOscillator {
+    #[wasm_bindgen(constructor)]
+    #[must_use]
+    pub fn new_js(
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+        ma_type: Option<MovingAverageType>,
+    ) -> Self {
+        Self::new(fast_period, slow_period, signal_period, ma_type)
+    }
+
+    #[wasm_bindgen(js_name = "name")]
+    pub fn name_js(&self) -> String {
+        self.name()
+    }
+
+    #[wasm_bindgen(getter, js_name = "fastPeriod")]
+    pub const fn fast_period_js(&self) -> usize {
+        self.fast_period
+    }
+
+    #[wasm_bindgen(getter, js_name = "slowPeriod")]
+    pub const fn slow_period_js(&self) -> usize {
+        self.slow_period
+    }
+
+    #[wasm_bindgen(getter, js_name = "signalPeriod")]
+    pub const fn signal_period_js(&self) -> usize {
+        self.signal_period
+    }
+
+    #[wasm_bindgen(getter, js_name = "hasInputs")]
+    pub fn has_inputs_js(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub const fn signal(&self) -> f64 {
+        self.signal
+    }
+
+    #[wasm_bindgen(getter, js_name = "histogram")]
+    pub fn histogram_js(&self) -> f64 {
+        self.histogram()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub const fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[wasm_bindgen(js_name = "updateRaw")]
+    pub fn update_raw_js(&mut self, high: f64, low: f64, close: f64, volume: f64) {
+        self.update_raw(high, low, close, volume);
+    }
+
+    #[wasm_bindgen(js_name = "handleBar")]
+    pub fn handle_bar_js(&mut self, bar: JsValue) -> Result<(), JsValue> {
+        let bar: Bar = serde_wasm_bindgen::from_value(bar)?;
+        self.handle_bar(&bar);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "reset")]
+    pub fn reset_js(&mut self) {
+        self.reset();
+    }
+}
@@ -0,0 +1,97 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::data::Bar;
+use wasm_bindgen::{prelude::*, JsValue};
+
+use crate::{
+    average::MovingAverageType, indicator::Indicator, volatility::bollinger_bands::BollingerBands,
+};
+
+#[wasm_bindgen]
+impl BollingerBands {
+    #[wasm_bindgen(constructor)]
+    #[must_use]
+    pub fn new_js(period: usize, k: f64, ma_type: Option<MovingAverageType>) -> Self {
+        Self::new(period, k, ma_type)
+    }
+
+    #[wasm_bindgen(js_name = "name")]
+    pub fn name_js(&self) -> String {
+        self.name()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub const fn period(&self) -> usize {
+        self.period
+    }
+
+    #[wasm_bindgen(getter)]
+    pub const fn k(&self) -> f64 {
+        self.k
+    }
+
+    #[wasm_bindgen(getter, js_name = "hasInputs")]
+    pub fn has_inputs_js(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub const fn middle(&self) -> f64 {
+        self.middle
+    }
+
+    #[wasm_bindgen(getter)]
+    pub const fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    #[wasm_bindgen(getter)]
+    pub const fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    #[wasm_bindgen(getter, js_name = "percentB")]
+    pub const fn percent_b_js(&self) -> f64 {
+        self.percent_b
+    }
+
+    #[wasm_bindgen(getter)]
+    pub const fn bandwidth(&self) -> f64 {
+        self.bandwidth
+    }
+
+    #[wasm_bindgen(getter)]
+    pub const fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[wasm_bindgen(js_name = "updateRaw")]
+    pub fn update_raw_js(&mut self, close: f64) {
+        self.update_raw(close);
+    }
+
+    #[wasm_bindgen(js_name = "handleBar")]
+    pub fn handle_bar_js(&mut self, bar: JsValue) -> Result<(), JsValue> {
+        let bar: Bar = serde_wasm_bindgen::from_value(bar)?;
+        self.handle_bar(&bar);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "reset")]
+    pub fn reset_js(&mut self) {
+        self.reset();
+    }
+}